@@ -1,57 +1,194 @@
-use fluent::{FluentBundle, FluentResource};
-use std::{collections::BTreeMap, error::Error, fmt::Write, fs, path::Path};
+use fluent::{FluentArgs, FluentBundle, FluentResource};
+use fluent_syntax::ast;
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    error::Error,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
 use unic_langid::LanguageIdentifier;
 
 pub struct Context {
     lang_bundles: BTreeMap<LanguageIdentifier, FluentBundle<FluentResource>>,
+    /// For each available locale, the ordered chain of locales (most to least
+    /// specific, ending in `default_locale`) to try when a message is absent.
+    fallbacks: BTreeMap<LanguageIdentifier, Vec<LanguageIdentifier>>,
+    default_locale: LanguageIdentifier,
+    pseudo: bool,
+    strict: bool,
+    report: RefCell<Report>,
 }
 
+/// Synthetic locale tag used for pseudolocalization.
+const PSEUDO_LOCALE: &str = "en-XA";
+
 impl Context {
+    /// Loads `{domain}.ftl` for each locale found under `i18n_dirs`, layering
+    /// `shared_domains` underneath it. For each required file, every root is
+    /// checked in order and a later root's copy overrides an earlier one; a
+    /// root that doesn't touch a given file falls through to an earlier root
+    /// that does.
+    ///
+    /// In `strict` mode, problems are accumulated into [`Context::report`]
+    /// instead of printed, so CI can gate on [`Report::is_ok`].
     pub fn new(
-        i18n_dir: impl AsRef<Path>,
+        i18n_dirs: impl IntoIterator<Item = impl AsRef<Path>>,
+        shared_domains: impl IntoIterator<Item = impl AsRef<str>>,
         domain: impl AsRef<str>,
+        default_locale: LanguageIdentifier,
+        strict: bool,
     ) -> Result<Self, Box<dyn Error>> {
-        let mut lang_files = BTreeMap::new();
-        for entry in fs::read_dir(i18n_dir)? {
-            let entry = entry?;
-            let file_type = entry.file_type()?;
-            if !file_type.is_dir() {
-                continue;
+        let domain = domain.as_ref().replace('-', "_");
+        let shared_domains: Vec<String> = shared_domains
+            .into_iter()
+            .map(|d| d.as_ref().replace('-', "_"))
+            .collect();
+
+        let mut locale_dirs: BTreeMap<LanguageIdentifier, Vec<PathBuf>> = BTreeMap::new();
+        for i18n_dir in i18n_dirs {
+            for entry in fs::read_dir(i18n_dir)? {
+                let entry = entry?;
+                let file_type = entry.file_type()?;
+                if !file_type.is_dir() {
+                    continue;
+                }
+                let name = entry
+                    .file_name()
+                    .into_string()
+                    .map_err(|err| format!("invalid UTF-8: {:?}", err))?;
+                let lang: LanguageIdentifier = name.parse()?;
+                // Roots are visited in order, so later roots sort later here too.
+                locale_dirs.entry(lang).or_default().push(entry.path());
             }
-            let name = entry
-                .file_name()
-                .into_string()
-                .map_err(|err| format!("invalid UTF-8: {:?}", err))?;
-            let lang: LanguageIdentifier = name.parse()?;
-            let path = entry
-                .path()
-                .join(&format!("{}.ftl", domain.as_ref().replace("-", "_")));
-            lang_files.insert(lang, path);
         }
 
+        let mut report = Report::default();
         let mut lang_bundles = BTreeMap::new();
-        for (lang, path) in lang_files {
-            let source = fs::read_to_string(&path)?;
-            let res = match FluentResource::try_new(source) {
-                Ok(res) => res,
-                Err((res, errs)) => {
+        let mut message_ids = BTreeMap::new();
+        for (lang, dirs) in locale_dirs {
+            let mut bundle = FluentBundle::new(vec![lang.clone()]);
+            let mut ids = BTreeSet::new();
+
+            for shared_domain in &shared_domains {
+                let filename = format!("{}.ftl", shared_domain);
+                let Some(path) = find_overriding_file(&dirs, &filename) else {
+                    continue;
+                };
+                let res = Self::load_resource(&path, &lang, strict, &mut report)?;
+                ids.extend(message_ids_of(&res));
+                bundle.add_resource_overriding(res);
+            }
+
+            let filename = format!("{}.ftl", domain);
+            match find_overriding_file(&dirs, &filename) {
+                Some(path) => {
+                    let res = Self::load_resource(&path, &lang, strict, &mut report)?;
+                    ids.extend(message_ids_of(&res));
+                    bundle.add_resource_overriding(res);
+                }
+                None => {
+                    if strict {
+                        report.problems.push(Problem::MissingFile {
+                            locale: lang.clone(),
+                            filename: filename.clone(),
+                        });
+                    } else {
+                        eprintln!(
+                            "no {} found for locale {} in any i18n_dir root",
+                            filename, lang
+                        );
+                    }
+                }
+            }
+
+            message_ids.insert(lang.clone(), ids);
+            lang_bundles.insert(lang, bundle);
+        }
+
+        match message_ids.get(&default_locale).cloned() {
+            Some(default_ids) => {
+                for (lang, ids) in &message_ids {
+                    let missing: BTreeSet<&String> = default_ids.difference(ids).collect();
+                    if strict {
+                        for message_id in &missing {
+                            report.problems.push(Problem::MissingMessage {
+                                message_id: (*message_id).clone(),
+                                locale: lang.clone(),
+                            });
+                        }
+                    }
+                    report.coverage.push(LocaleCoverage {
+                        locale: lang.clone(),
+                        present: default_ids.len() - missing.len(),
+                        total: default_ids.len(),
+                    });
+                }
+            }
+            None => {
+                if strict {
+                    report.problems.push(Problem::MissingDefaultLocale {
+                        locale: default_locale.clone(),
+                    });
+                } else {
                     eprintln!(
-                        "failed to parse {} with {} errors:",
-                        path.display(),
-                        errs.len()
+                        "default locale {} has no loaded translations",
+                        default_locale
                     );
-                    for err in errs {
-                        eprintln!(" - {}", err);
-                    }
-                    res
                 }
-            };
-            let mut bundle = FluentBundle::new(vec![lang.clone()]);
-            match bundle.add_resource(res) {
-                Ok(()) => {}
-                Err(errs) => {
+            }
+        }
+
+        let fallbacks = lang_bundles
+            .keys()
+            .map(|lang| (lang.clone(), fallback_chain(lang, &default_locale)))
+            .collect();
+
+        Ok(Self {
+            lang_bundles,
+            fallbacks,
+            default_locale,
+            pseudo: false,
+            strict,
+            report: RefCell::new(report),
+        })
+    }
+
+    /// Enables injecting a synthetic `en-XA` locale, derived from the
+    /// default locale, into every `FluentString::get` result.
+    pub fn pseudolocalize(mut self, enabled: bool) -> Self {
+        self.pseudo = enabled;
+        self
+    }
+
+    /// Returns a snapshot of every problem and coverage figure accumulated
+    /// so far. In non-strict mode this only reflects translation-coverage
+    /// figures computed at load time, since parse/add-resource/format
+    /// problems are printed to stderr instead of recorded.
+    pub fn report(&self) -> Report {
+        self.report.borrow().clone()
+    }
+
+    fn load_resource(
+        path: &Path,
+        lang: &LanguageIdentifier,
+        strict: bool,
+        report: &mut Report,
+    ) -> Result<FluentResource, Box<dyn Error>> {
+        let source = fs::read_to_string(path)?;
+        let res = match FluentResource::try_new(source) {
+            Ok(res) => res,
+            Err((res, errs)) => {
+                if strict {
+                    report.problems.push(Problem::Parse {
+                        locale: lang.clone(),
+                        path: path.to_path_buf(),
+                        errors: errs.iter().map(ToString::to_string).collect(),
+                    });
+                } else {
                     eprintln!(
-                        "failed to add resource {} with {} errors:",
+                        "failed to parse {} with {} errors:",
                         path.display(),
                         errs.len()
                     );
@@ -59,49 +196,413 @@ impl Context {
                         eprintln!(" - {}", err);
                     }
                 }
+                res
+            }
+        };
+
+        let duplicates = duplicate_message_ids(&res);
+        if !duplicates.is_empty() {
+            if strict {
+                report.problems.push(Problem::AddResource {
+                    locale: lang.clone(),
+                    path: path.to_path_buf(),
+                    errors: duplicates
+                        .iter()
+                        .map(|id| format!("message `{}` is defined more than once", id))
+                        .collect(),
+                });
+            } else {
+                eprintln!(
+                    "{} has {} duplicate message ids:",
+                    path.display(),
+                    duplicates.len()
+                );
+                for id in &duplicates {
+                    eprintln!(" - {}", id);
+                }
             }
-            lang_bundles.insert(lang, bundle);
         }
 
-        Ok(Self { lang_bundles })
+        Ok(res)
     }
 }
 
+/// Finds the highest-priority root in `dirs` (later roots override earlier
+/// ones) that has a copy of `filename`, falling through to an earlier root
+/// when a later one doesn't touch it.
+fn find_overriding_file(dirs: &[PathBuf], filename: &str) -> Option<PathBuf> {
+    dirs.iter().rev().find_map(|dir| {
+        let path = dir.join(filename);
+        path.exists().then_some(path)
+    })
+}
+
+/// Collects the ids of every `message` entry in a parsed resource, used to
+/// compute per-locale translation coverage against the default locale.
+fn message_ids_of(res: &FluentResource) -> BTreeSet<String> {
+    res.entries()
+        .filter_map(|entry| match entry {
+            ast::Entry::Message(message) => Some(message.id.name.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Ids of `message` entries that occur more than once within a single
+/// resource, which `add_resource_overriding` silently resolves in favor of
+/// the last definition instead of flagging as a mistake.
+fn duplicate_message_ids(res: &FluentResource) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    let mut duplicates = BTreeSet::new();
+    for entry in res.entries() {
+        if let ast::Entry::Message(message) = entry {
+            let id = message.id.name.to_string();
+            if !seen.insert(id.clone()) {
+                duplicates.insert(id);
+            }
+        }
+    }
+    duplicates.into_iter().collect()
+}
+
+/// A single parse/resource/format/coverage problem encountered while
+/// loading or formatting translations, recorded instead of printed when
+/// [`Context`] is constructed in strict mode.
+#[derive(Clone, Debug)]
+pub enum Problem {
+    Parse {
+        locale: LanguageIdentifier,
+        path: PathBuf,
+        errors: Vec<String>,
+    },
+    AddResource {
+        locale: LanguageIdentifier,
+        path: PathBuf,
+        errors: Vec<String>,
+    },
+    Format {
+        message_id: String,
+        locale: LanguageIdentifier,
+        errors: Vec<String>,
+    },
+    MissingMessage {
+        message_id: String,
+        locale: LanguageIdentifier,
+    },
+    /// No root under `i18n_dirs` had a copy of the locale's required
+    /// `{domain}.ftl`.
+    MissingFile {
+        locale: LanguageIdentifier,
+        filename: String,
+    },
+    /// `default_locale` doesn't match any locale directory found under
+    /// `i18n_dirs`, so coverage can't be computed against it.
+    MissingDefaultLocale { locale: LanguageIdentifier },
+}
+
+/// How much of the default locale's message set a given locale covers.
+#[derive(Clone, Debug)]
+pub struct LocaleCoverage {
+    pub locale: LanguageIdentifier,
+    pub present: usize,
+    pub total: usize,
+}
+
+impl LocaleCoverage {
+    pub fn is_complete(&self) -> bool {
+        self.present == self.total
+    }
+
+    pub fn percent(&self) -> f64 {
+        if self.total == 0 {
+            100.0
+        } else {
+            (self.present as f64 / self.total as f64) * 100.0
+        }
+    }
+}
+
+/// A structured record of everything that went wrong (or didn't) while
+/// loading and formatting translations, for use in CI gating instead of
+/// scraping stderr.
+#[derive(Clone, Debug, Default)]
+pub struct Report {
+    pub problems: Vec<Problem>,
+    pub coverage: Vec<LocaleCoverage>,
+}
+
+impl Report {
+    /// True when there are no recorded problems and every locale has 100%
+    /// coverage of the default locale's messages.
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty() && self.coverage.iter().all(LocaleCoverage::is_complete)
+    }
+
+    /// A process exit code suitable for CI gating: `0` when [`Self::is_ok`],
+    /// `1` otherwise.
+    pub fn exit_code(&self) -> i32 {
+        i32::from(!self.is_ok())
+    }
+}
+
+/// Ordered fallback chain for `lang`, truncating subtags from most to
+/// least specific (variants, then region, then script), ending in
+/// `default`.
+fn fallback_chain(
+    lang: &LanguageIdentifier,
+    default: &LanguageIdentifier,
+) -> Vec<LanguageIdentifier> {
+    let mut chain = vec![lang.clone()];
+    let mut current = lang.clone();
+    loop {
+        let truncated = if current.variants().next().is_some() {
+            LanguageIdentifier::from_parts(current.language, current.script, current.region, &[])
+        } else if current.region.is_some() {
+            LanguageIdentifier::from_parts(current.language, current.script, None, &[])
+        } else if current.script.is_some() {
+            LanguageIdentifier::from_parts(current.language, None, None, &[])
+        } else {
+            break;
+        };
+        current = truncated.clone();
+        if !chain.contains(&truncated) {
+            chain.push(truncated);
+        }
+    }
+    if !chain.contains(default) {
+        chain.push(default.clone());
+    }
+    chain
+}
+
+#[cfg(test)]
+mod fallback_chain_tests {
+    use super::*;
+
+    #[test]
+    fn truncates_variants_then_region_then_script() {
+        let lang: LanguageIdentifier = "zh-Hant-HK".parse().unwrap();
+        let default: LanguageIdentifier = "en".parse().unwrap();
+        let expected: Vec<LanguageIdentifier> = ["zh-Hant-HK", "zh-Hant", "zh", "en"]
+            .iter()
+            .map(|tag| tag.parse().unwrap())
+            .collect();
+        assert_eq!(fallback_chain(&lang, &default), expected);
+    }
+
+    #[test]
+    fn default_locale_chains_to_itself() {
+        let default: LanguageIdentifier = "en".parse().unwrap();
+        assert_eq!(fallback_chain(&default, &default), vec![default]);
+    }
+}
+
+/// A resolved Fluent value, and whether it only came from falling back to
+/// the default locale rather than the target locale's own bundle.
+#[derive(Clone, Debug)]
+pub struct Resolved {
+    pub value: String,
+    pub from_default: bool,
+}
+
 #[derive(Clone, Debug)]
 pub struct FluentString(pub &'static str);
 
 impl FluentString {
-    pub fn get(&self, ctx: &Context) -> BTreeMap<LanguageIdentifier, String> {
+    pub fn get(&self, ctx: &Context, args: &FluentArgs) -> BTreeMap<LanguageIdentifier, Resolved> {
         let mut results = BTreeMap::new();
-        for (lang, bundle) in ctx.lang_bundles.iter() {
-            let Some(msg) = bundle.get_message(self.0) else {
-                continue;
-            };
-            let Some(pat) = msg.value() else { continue };
-            let mut errs = Vec::new();
-            let result = bundle.format_pattern(&pat, None, &mut errs);
-            if !errs.is_empty() {
-                eprintln!(
-                    "{} errors when formatting {} for lang {}:",
-                    errs.len(),
-                    self.0,
-                    lang
-                );
-                for err in errs {
-                    eprintln!(" - {}", err);
+        for target in ctx.lang_bundles.keys() {
+            let chain = ctx
+                .fallbacks
+                .get(target)
+                .expect("fallback chain precomputed for every available locale");
+
+            for resolved_lang in chain {
+                let Some(bundle) = ctx.lang_bundles.get(resolved_lang) else {
+                    continue;
+                };
+                let Some(msg) = bundle.get_message(self.0) else {
+                    continue;
+                };
+                let Some(pat) = msg.value() else { continue };
+                let mut errs = Vec::new();
+                let value = bundle.format_pattern(&pat, Some(args), &mut errs);
+                if !errs.is_empty() {
+                    if ctx.strict {
+                        ctx.report.borrow_mut().problems.push(Problem::Format {
+                            message_id: self.0.to_string(),
+                            locale: target.clone(),
+                            errors: errs.iter().map(ToString::to_string).collect(),
+                        });
+                    } else {
+                        eprintln!(
+                            "{} errors when formatting {} for lang {} (resolved via {}):",
+                            errs.len(),
+                            self.0,
+                            target,
+                            resolved_lang
+                        );
+                        for err in errs {
+                            eprintln!(" - {}", err);
+                        }
+                    }
                 }
+                results.insert(
+                    target.clone(),
+                    Resolved {
+                        value: value.into(),
+                        from_default: target != resolved_lang && resolved_lang == &ctx.default_locale,
+                    },
+                );
+                break;
+            }
+        }
+
+        if ctx.pseudo {
+            if let Some(default_resolved) = results.get(&ctx.default_locale) {
+                let pseudo_lang: LanguageIdentifier =
+                    PSEUDO_LOCALE.parse().expect("PSEUDO_LOCALE is a valid language tag");
+                results.insert(
+                    pseudo_lang,
+                    Resolved {
+                        value: pseudolocalize(&default_resolved.value),
+                        from_default: false,
+                    },
+                );
             }
-            results.insert(lang.clone(), result.into());
         }
+
         results
     }
 }
 
+/// Pseudolocalizes `value`: accents ASCII letters, pads to ~140-160% of
+/// the original length, and brackets the result. Each `;`-separated
+/// segment is transformed independently, `{ ... }` placeables are left
+/// untouched, and a trailing `;` (as Desktop Entry list values require) is
+/// preserved.
+fn pseudolocalize(value: &str) -> String {
+    let mut result = value
+        .split_terminator(';')
+        .map(pseudolocalize_segment)
+        .collect::<Vec<_>>()
+        .join(";");
+    if value.ends_with(';') {
+        result.push(';');
+    }
+    result
+}
+
+fn pseudolocalize_segment(segment: &str) -> String {
+    let mut transformed = String::new();
+    let mut placeable_depth = 0u32;
+    for ch in segment.chars() {
+        match ch {
+            '{' => {
+                placeable_depth += 1;
+                transformed.push(ch);
+            }
+            '}' => {
+                placeable_depth = placeable_depth.saturating_sub(1);
+                transformed.push(ch);
+            }
+            _ if placeable_depth > 0 => transformed.push(ch),
+            _ => transformed.push(pseudo_accent(ch)),
+        }
+    }
+
+    let target_len = (transformed.chars().count() * 3) / 2;
+    const FILLER: &str = "áéíóúäëïöü";
+    let mut filler = FILLER.chars().cycle();
+    while transformed.chars().count() < target_len {
+        transformed.push(filler.next().unwrap());
+    }
+
+    format!("[{}]", transformed)
+}
+
+/// Maps an ASCII letter to a visually-similar accented equivalent; leaves
+/// everything else (digits, punctuation, non-ASCII) untouched.
+fn pseudo_accent(ch: char) -> char {
+    match ch {
+        'a' => 'á',
+        'A' => 'Á',
+        'e' => 'é',
+        'E' => 'É',
+        'i' => 'í',
+        'I' => 'Í',
+        'o' => 'ó',
+        'O' => 'Ó',
+        'u' => 'ú',
+        'U' => 'Ú',
+        's' => 'š',
+        'S' => 'Š',
+        'c' => 'ç',
+        'C' => 'Ç',
+        'n' => 'ñ',
+        'N' => 'Ñ',
+        'z' => 'ž',
+        'Z' => 'Ž',
+        'y' => 'ý',
+        'Y' => 'Ý',
+        'g' => 'ğ',
+        'G' => 'Ğ',
+        'r' => 'ř',
+        'R' => 'Ř',
+        't' => 'ť',
+        'T' => 'Ť',
+        'd' => 'ð',
+        'D' => 'Ð',
+        'l' => 'ł',
+        'L' => 'Ł',
+        'w' => 'ŵ',
+        'W' => 'Ŵ',
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod pseudolocalize_tests {
+    use super::*;
+
+    #[test]
+    fn leaves_placeable_text_untouched() {
+        let result = pseudolocalize_segment("Hi { $name }");
+        assert!(result.contains("{ $name }"));
+    }
+
+    #[test]
+    fn accents_literal_text() {
+        let result = pseudolocalize_segment("see");
+        assert!(result.starts_with("[šé"));
+    }
+
+    #[test]
+    fn transforms_each_semicolon_separated_keyword_independently() {
+        let result = pseudolocalize("foo;bar;");
+        assert!(result.ends_with(';'));
+        let keywords: Vec<&str> = result.split_terminator(';').collect();
+        assert_eq!(keywords.len(), 2);
+        for keyword in keywords {
+            assert!(keyword.starts_with('['));
+            assert!(keyword.ends_with(']'));
+        }
+    }
+
+    #[test]
+    fn pads_to_roughly_150_percent() {
+        let result = pseudolocalize_segment("hello");
+        let inner_len = result.chars().count() - 2;
+        assert_eq!(inner_len, 7);
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct App {
     name: FluentString,
     comment: Option<FluentString>,
     keywords: Option<FluentString>,
+    args: BTreeMap<&'static str, String>,
 }
 
 impl App {
@@ -110,6 +611,7 @@ impl App {
             name,
             comment: None,
             keywords: None,
+            args: BTreeMap::new(),
         }
     }
 
@@ -123,6 +625,22 @@ impl App {
         self
     }
 
+    /// Binds a Fluent variable (e.g. `{ $version }`) used by this app's
+    /// messages, letting one template/`.ftl` pair produce per-release or
+    /// per-edition output without duplicating strings.
+    pub fn arg(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.args.insert(name, value.into());
+        self
+    }
+
+    fn fluent_args(&self) -> FluentArgs<'_> {
+        let mut args = FluentArgs::new();
+        for (name, value) in &self.args {
+            args.set(*name, value.clone());
+        }
+        args
+    }
+
     pub fn expand_desktop(
         &self,
         template_path: impl AsRef<Path>,
@@ -130,6 +648,7 @@ impl App {
     ) -> Result<String, Box<dyn Error>> {
         let template_path = template_path.as_ref();
         let template = freedesktop_entry_parser::parse_entry(template_path)?;
+        let fluent_args = self.fluent_args();
         let mut s = String::new();
         for (name, section) in template.sections() {
             writeln!(s, "[{}]", name)?;
@@ -161,14 +680,20 @@ impl App {
                             .into());
                         }
                         None => {
-                            // Inject translated names
-                            for (lang, value) in fluent.get(ctx) {
+                            // Inject translated names, skipping locales that only
+                            // fell back to the default and duplicate the base value.
+                            for (lang, resolved) in fluent.get(ctx, &fluent_args) {
+                                if resolved.from_default
+                                    && values.iter().any(|value| value == &resolved.value)
+                                {
+                                    continue;
+                                }
                                 writeln!(
                                     s,
                                     "{}[{}]={}",
                                     key.key,
                                     lang.to_string().replace("-", "_"),
-                                    value
+                                    resolved.value
                                 )?;
                             }
                         }
@@ -191,6 +716,7 @@ impl App {
         let template = fs::File::open(template_path)?;
 
         let mut element = Element::parse(template)?;
+        let fluent_args = self.fluent_args();
 
         let expand_locale = |element: &mut Element,
                              tag: &str,
@@ -230,12 +756,20 @@ impl App {
                 .into());
             };
 
-            for (lang, value) in fluent.get(ctx) {
+            let base_text = element.children[index]
+                .as_element()
+                .and_then(|e| e.get_text())
+                .map(|t| t.to_string());
+
+            for (lang, resolved) in fluent.get(ctx, &fluent_args) {
+                if resolved.from_default && base_text.as_deref() == Some(resolved.value.as_str()) {
+                    continue;
+                }
                 let mut child = Element::new(tag);
                 child
                     .attributes
                     .insert("lang".to_string(), lang.to_string().replace("-", "_"));
-                child.children.push(XMLNode::Text(value));
+                child.children.push(XMLNode::Text(resolved.value));
                 index += 1;
                 element.children.insert(index, XMLNode::Element(child));
             }
@@ -252,8 +786,18 @@ impl App {
             let kw_elem = element.get_mut_child("keywords").ok_or_else(|| {
                 format!("template {} is missing keywords", template_path.display())
             })?;
-            for (lang, values) in keywords.get(ctx) {
-                for value in values.split_terminator(';') {
+            let base_keywords: Vec<String> = kw_elem
+                .children
+                .iter()
+                .filter_map(|c| c.as_element())
+                .filter(|e| e.attributes.is_empty())
+                .filter_map(|e| e.get_text().map(|t| t.to_string()))
+                .collect();
+            for (lang, resolved) in keywords.get(ctx, &fluent_args) {
+                for value in resolved.value.split_terminator(';') {
+                    if resolved.from_default && base_keywords.iter().any(|b| b == value) {
+                        continue;
+                    }
                     let mut child = Element::new("keyword");
                     child
                         .attributes